@@ -1,22 +1,96 @@
-use futures_util::{
-    future::{self, join_all},
-    stream::{SelectAll, StreamExt},
-    Stream,
+mod marquee;
+mod output;
+mod progress;
+mod socket;
+
+use futures_util::stream::StreamExt;
+use std::{collections::HashMap, error::Error, fmt::Display, time::Duration};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tokio_stream::wrappers::IntervalStream;
+use zbus::{
+    fdo::DBusProxy,
+    proxy,
+    zvariant::{DeserializeDict, Type},
+    Connection,
 };
-use std::{collections::HashMap, error::Error, fmt::Display};
-use zbus::{fdo::DBusProxy, proxy, zvariant::OwnedValue, Connection};
+
+use output::OutputMode;
+use progress::Progress;
+
+/// How often `PlayerEvent::Tick` fires to advance the marquee scroll offset.
+const SCROLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The standard xesam/mpris metadata keys, as published on the `Metadata`
+/// property of `org.mpris.MediaPlayer2.Player`. All fields are optional
+/// since players are free to omit any of them.
+#[derive(Debug, Clone, Default, DeserializeDict, Type)]
+#[zvariant(signature = "a{sv}")]
+struct Metadata {
+    #[zvariant(rename = "xesam:title")]
+    title: Option<String>,
+    #[zvariant(rename = "xesam:artist")]
+    artist: Option<Vec<String>>,
+    #[zvariant(rename = "xesam:album")]
+    album: Option<String>,
+    #[zvariant(rename = "xesam:albumArtist")]
+    album_artist: Option<Vec<String>>,
+    #[zvariant(rename = "xesam:trackNumber")]
+    track_number: Option<i64>,
+    #[zvariant(rename = "xesam:discNumber")]
+    disc_number: Option<i64>,
+    #[zvariant(rename = "xesam:audioBPM")]
+    audio_bpm: Option<i64>,
+    #[zvariant(rename = "mpris:length")]
+    length: Option<i64>,
+    #[zvariant(rename = "mpris:artUrl")]
+    art_url: Option<String>,
+}
 
 #[proxy(
     default_path = "/org/mpris/MediaPlayer2",
     interface = "org.mpris.MediaPlayer2.Player"
 )]
 trait Player {
+    fn play_pause(&self) -> zbus::Result<()>;
+    fn next(&self) -> zbus::Result<()>;
+    fn previous(&self) -> zbus::Result<()>;
+
     #[zbus(property)]
     fn can_control(&self) -> zbus::Result<bool>;
     #[zbus(property)]
-    fn metadata(&self) -> zbus::Result<HashMap<String, OwnedValue>>;
+    fn metadata(&self) -> zbus::Result<Metadata>;
     #[zbus(property)]
     fn volume(&self) -> zbus::Result<f64>;
+    #[zbus(property)]
+    fn set_volume(&self, volume: f64) -> zbus::Result<()>;
+    #[zbus(property)]
+    fn playback_status(&self) -> zbus::Result<String>;
+    #[zbus(property)]
+    fn position(&self) -> zbus::Result<i64>;
+    #[zbus(property)]
+    fn rate(&self) -> zbus::Result<f64>;
+
+    #[zbus(signal)]
+    fn seeked(&self, position: i64) -> zbus::Result<()>;
+}
+
+/// `org.mpris.MediaPlayer2.playerctld`'s own interface, layered on top of the
+/// standard MPRIS players it proxies. When present on the bus it tracks which
+/// player was most recently active, which is a better "current player"
+/// signal than the arbitrary order `ListNames` returns.
+///
+/// Only the recency signal we read (`PlayerNames`) is exposed here. Mutating
+/// which player is primary (`Shift`/`Unshift`) is out of scope until
+/// something in this crate needs to change playerctld's active player rather
+/// than just reflect it.
+#[proxy(
+    default_service = "org.mpris.MediaPlayer2.playerctld",
+    default_path = "/org/mpris/MediaPlayer2",
+    interface = "com.github.altdesktop.playerctld"
+)]
+trait Playerctld {
+    #[zbus(property)]
+    fn player_names(&self) -> zbus::Result<Vec<String>>;
 }
 
 #[derive(Debug)]
@@ -30,29 +104,57 @@ impl Display for TitleParseError {
 
 impl Error for TitleParseError {}
 
-impl PlayerProxy<'_> {
-    async fn get_title(&self) -> Result<String, TitleParseError> {
-        let m = self.metadata().await.map_err(|_| TitleParseError {})?;
-        let artists = m
-            .get("xesam:artist")
-            .unwrap()
-            .clone()
-            .try_into()
-            .map(|a: Vec<String>| a.join(", "));
-        let title = TryInto::<String>::try_into(m.get("xesam:title").unwrap().clone());
-        match (artists, title) {
-            (Ok(a), Ok(t)) => Ok(format!("{} - {}", a, t)),
-            (_, Ok(t)) => Ok(t),
-            (Ok(a), _) => Ok(a),
-            _ => Err(TitleParseError {}),
+impl Metadata {
+    /// Render as "artist - title", falling back to whichever of the two is
+    /// present.
+    fn title_line(self) -> Result<String, TitleParseError> {
+        match (self.artist.map(|a| a.join(", ")), self.title) {
+            (Some(a), Some(t)) => Ok(format!("{} - {}", a, t)),
+            (None, Some(t)) => Ok(t),
+            (Some(a), None) => Ok(a),
+            (None, None) => Err(TitleParseError {}),
         }
     }
 }
 
+/// A playback action that can be dispatched to a single player by service name.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Command {
+    PlayPause,
+    Next,
+    Previous,
+    SetVolume(f64),
+}
+
+/// Resolve `name` to a `PlayerProxy` and invoke the method matching `command`.
+async fn send_command(
+    conn: &Connection,
+    name: &str,
+    command: Command,
+) -> Result<(), Box<dyn Error>> {
+    let player = PlayerProxy::new(conn, name.to_string()).await?;
+    match command {
+        Command::PlayPause => player.play_pause().await?,
+        Command::Next => player.next().await?,
+        Command::Previous => player.previous().await?,
+        Command::SetVolume(volume) => player.set_volume(volume).await?,
+    }
+    Ok(())
+}
+
+/// A targeted change to one player, or a marquee tick affecting all of them.
+///
+/// Carrying the service name lets the main loop update a single entry in the
+/// `players` map instead of re-querying every player over D-Bus on every
+/// single change.
 enum PlayerEvent {
-    Names,
-    Metadata,
-    Volume,
+    NameAdded(String),
+    NameRemoved(String),
+    Metadata(String),
+    Volume(String),
+    PlaybackStatus(String),
+    Seeked(String, i64),
+    Tick,
 }
 
 const SERVICE_PREFIX: &str = "org.mpris.MediaPlayer2.";
@@ -61,26 +163,48 @@ struct PlayerData {
     service_name: String,
     title: Option<String>,
     volume: Option<f64>,
+    /// Grapheme offset into `title` for the marquee display mode; reset to
+    /// 0 whenever this player's metadata changes.
+    scroll_offset: usize,
+    /// Interpolated playback position; only tracked with `--progress`, and
+    /// starting from 0 if the player's `Position` can't be read.
+    position: Option<Progress>,
+    /// Cached `playback_rank` of this player's last-known `PlaybackStatus`,
+    /// kept up to date from that event's own fetch so the fallback sort in
+    /// `order_players` doesn't have to re-query every player.
+    rank: u8,
 }
 
-impl Display for PlayerData {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl PlayerData {
+    /// `title`, scrolled to fit `width` grapheme clusters if set.
+    fn display_title(&self, width: Option<usize>) -> Option<String> {
+        self.title.as_deref().map(|t| match width {
+            Some(w) => marquee::scroll(t, w, self.scroll_offset),
+            None => t.to_string(),
+        })
+    }
+
+    fn display(&self, width: Option<usize>, show_progress: bool) -> String {
         let service_name = self
             .service_name
             .strip_prefix(SERVICE_PREFIX)
             .unwrap_or(self.service_name.as_str());
-        match &self.title {
-            Some(t) => match self.volume {
-                Some(v) => {
-                    write!(f, "{}[{}]: {}", service_name, v, t)
-                }
-                None => {
-                    write!(f, "{}: {}", service_name, t)
-                }
-            },
-            None => {
-                write!(f, "{}: Nothing", service_name)
-            }
+        let progress = self.progress_suffix(show_progress);
+        match (self.display_title(width), self.volume) {
+            (Some(t), Some(v)) => format!("{}[{}]: {}{}", service_name, v, t, progress),
+            (Some(t), None) => format!("{}: {}{}", service_name, t, progress),
+            (None, _) => format!("{}: Nothing", service_name),
+        }
+    }
+
+    fn progress_suffix(&self, show_progress: bool) -> String {
+        if show_progress {
+            self.position
+                .as_ref()
+                .map(|p| format!(" ({})", p.render()))
+                .unwrap_or_default()
+        } else {
+            String::new()
         }
     }
 }
@@ -88,93 +212,320 @@ impl Display for PlayerData {
 // Although we use `tokio` here, you can use any async runtime of choice.
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let mode = OutputMode::from_args();
+    let marquee_width = marquee::width_from_args();
+    let show_progress = progress::enabled_from_args();
+    let socket_path = socket::path_from_args();
     let conn = Connection::session().await?;
-    let mut combined = init_streams(&conn).await?;
-    let mut names = get_player_names(&conn).await?;
-    let mut data = get_data(&conn, &names).await?;
-    data.iter().for_each(|d| println!("{d}"));
-    while let Some(e) = combined.next().await {
-        match e {
-            PlayerEvent::Names => {
-                combined = init_streams(&conn).await?;
-                names = get_player_names(&conn).await?;
-                data = get_data(&conn, &names).await?;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(watch_name_owner_changes(conn.clone(), tx.clone()));
+    tokio::spawn(tick(tx.clone()));
+
+    let mut players: HashMap<String, PlayerData> = HashMap::new();
+    let mut subscriptions: HashMap<String, JoinHandle<()>> = HashMap::new();
+    for name in get_player_names(&conn).await? {
+        add_player(
+            &conn,
+            name,
+            &tx,
+            &mut players,
+            &mut subscriptions,
+            show_progress,
+        )
+        .await;
+    }
+    let mut order = order_players(&conn, &player_names(&players), &players).await;
+
+    mode.print_header();
+    print_current(&mode, &players, &order, marquee_width, show_progress);
+
+    let listener_conn = conn.clone();
+    let listener_path = socket_path.clone();
+    tokio::spawn(async move {
+        if let Err(e) = socket::listen(listener_conn, listener_path).await {
+            eprintln!("command socket: {e}");
+        }
+    });
+
+    loop {
+        tokio::select! {
+            Some(event) = rx.recv() => {
+                match event {
+                    PlayerEvent::NameAdded(name) => {
+                        add_player(
+                            &conn,
+                            name,
+                            &tx,
+                            &mut players,
+                            &mut subscriptions,
+                            show_progress,
+                        )
+                        .await;
+                        order = order_players(&conn, &player_names(&players), &players).await;
+                    }
+                    PlayerEvent::NameRemoved(name) => {
+                        remove_player(&name, &mut players, &mut subscriptions);
+                        order.retain(|n| n != &name);
+                    }
+                    PlayerEvent::Metadata(name) => {
+                        let (title, length) = fetch_title_and_length(&conn, &name).await;
+                        if let Some(data) = players.get_mut(&name) {
+                            data.title = title;
+                            data.scroll_offset = 0;
+                            data.position = if show_progress {
+                                fetch_progress(&conn, &name, length).await
+                            } else {
+                                None
+                            };
+                        }
+                    }
+                    PlayerEvent::Volume(name) => {
+                        if let Some(data) = players.get_mut(&name) {
+                            data.volume = fetch_volume(&conn, &name).await;
+                        }
+                        order = order_players(&conn, &order, &players).await;
+                    }
+                    PlayerEvent::PlaybackStatus(name) => {
+                        let status = fetch_status(&conn, &name).await;
+                        let playing = status.as_deref() == Some("Playing");
+                        if let Some(data) = players.get_mut(&name) {
+                            data.rank = playback_rank(status);
+                            match data.position.as_mut() {
+                                Some(position) => position.set_playing(playing),
+                                None if show_progress => {
+                                    let length = fetch_length(&conn, &name).await;
+                                    data.position = fetch_progress(&conn, &name, length).await;
+                                }
+                                None => {}
+                            }
+                        }
+                        order = order_players(&conn, &order, &players).await;
+                    }
+                    PlayerEvent::Seeked(name, position) => {
+                        if show_progress {
+                            if let Some(data) = players.get_mut(&name) {
+                                match data.position.as_mut() {
+                                    Some(progress) => progress.seek_to(position),
+                                    None => {
+                                        let length = fetch_length(&conn, &name).await;
+                                        data.position = fetch_progress(&conn, &name, length).await;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    PlayerEvent::Tick => {
+                        players.values_mut().for_each(|d| d.scroll_offset += 1);
+                    }
+                }
+                print_current(&mode, &players, &order, marquee_width, show_progress);
             }
-            _ => {
-                data = get_data(&conn, &names).await?;
+            _ = tokio::signal::ctrl_c() => {
+                let _ = std::fs::remove_file(&socket_path);
+                break;
             }
         }
-        data.iter().for_each(|d| println!("{d}"));
     }
 
     Ok(())
 }
 
-async fn init_streams(
-    conn: &Connection,
-) -> Result<SelectAll<std::pin::Pin<Box<dyn Stream<Item = PlayerEvent> + Send>>>, Box<dyn Error>> {
-    let dbus = DBusProxy::new(conn).await?;
-    let names = get_player_names(conn).await?;
-    let services = join_all(
-        names
-            .iter()
-            .map(|n| async move { PlayerProxy::new(conn, n.clone()).await.unwrap() }),
-    )
-    .await;
-    let mut combined = SelectAll::new();
-    combined.push(
-        dbus.receive_name_owner_changed()
-            .await?
-            .filter_map(|s| {
-                future::ready(match s.args() {
-                    Ok(a) => a
-                        .name
-                        .starts_with("org.mpris.MediaPlayer2.")
-                        .then_some(PlayerEvent::Names),
-                    Err(_) => None,
-                })
-            })
-            .boxed(),
-    );
-    for s in services.iter() {
-        combined.push(
-            s.receive_metadata_changed()
-                .await
-                .map(|_| PlayerEvent::Metadata)
-                .boxed(),
-        );
-    }
-    for s in services.iter() {
-        combined.push(
-            s.receive_volume_changed()
-                .await
-                .map(|_| PlayerEvent::Volume)
-                .boxed(),
-        );
-    }
-    Ok(combined)
-}
-
-async fn get_data(conn: &Connection, names: &[String]) -> Result<Vec<PlayerData>, Box<dyn Error>> {
-    Ok(join_all(names.iter().map(|n| async {
-        let dbus = PlayerProxy::new(conn, n.clone()).await;
-        match dbus {
-            Ok(player) => {
-                let t = player.get_title().await.ok();
-                let v = player.volume().await.ok();
-                PlayerData {
-                    service_name: n.clone(),
-                    title: t,
-                    volume: v,
+/// Forward `NameOwnerChanged` signals for MPRIS services as add/remove events.
+async fn watch_name_owner_changes(conn: Connection, tx: mpsc::UnboundedSender<PlayerEvent>) {
+    let Ok(dbus) = DBusProxy::new(&conn).await else {
+        return;
+    };
+    let Ok(mut changes) = dbus.receive_name_owner_changed().await else {
+        return;
+    };
+    while let Some(change) = changes.next().await {
+        let Ok(args) = change.args() else { continue };
+        if !args.name.starts_with(SERVICE_PREFIX) {
+            continue;
+        }
+        let name = args.name.to_string();
+        let event = if args.new_owner.is_none() {
+            PlayerEvent::NameRemoved(name)
+        } else {
+            PlayerEvent::NameAdded(name)
+        };
+        if tx.send(event).is_err() {
+            break;
+        }
+    }
+}
+
+/// Send `PlayerEvent::Tick` on `SCROLL_INTERVAL` to advance the marquee.
+async fn tick(tx: mpsc::UnboundedSender<PlayerEvent>) {
+    let mut ticks = IntervalStream::new(tokio::time::interval(SCROLL_INTERVAL));
+    while ticks.next().await.is_some() {
+        if tx.send(PlayerEvent::Tick).is_err() {
+            break;
+        }
+    }
+}
+
+/// Subscribe to `name`'s property changes and `Seeked` signal, forwarding
+/// each as a tagged `PlayerEvent` until the player's proxy stops producing
+/// them (e.g. because it left the bus).
+///
+/// The three property-change streams are independent of `Seeked` and of each
+/// other; if registering the `Seeked` match rule fails, that arm is just left
+/// disabled instead of bailing out of the whole task.
+async fn watch_player(conn: Connection, name: String, tx: mpsc::UnboundedSender<PlayerEvent>) {
+    let Ok(player) = PlayerProxy::new(&conn, name.clone()).await else {
+        return;
+    };
+    let mut metadata_changed = player.receive_metadata_changed().await;
+    let mut volume_changed = player.receive_volume_changed().await;
+    let mut status_changed = player.receive_playback_status_changed().await;
+    let mut seeked = player.receive_seeked().await.ok();
+    loop {
+        let event = tokio::select! {
+            Some(_) = metadata_changed.next() => PlayerEvent::Metadata(name.clone()),
+            Some(_) = volume_changed.next() => PlayerEvent::Volume(name.clone()),
+            Some(_) = status_changed.next() => PlayerEvent::PlaybackStatus(name.clone()),
+            Some(signal) = async { seeked.as_mut()?.next().await }, if seeked.is_some() => {
+                match signal.args() {
+                    Ok(args) => PlayerEvent::Seeked(name.clone(), args.position),
+                    Err(_) => continue,
                 }
             }
-            Err(_) => PlayerData {
-                service_name: n.clone(),
-                ..Default::default()
-            },
+            else => break,
+        };
+        if tx.send(event).is_err() {
+            break;
         }
-    }))
-    .await)
+    }
+}
+
+/// Fetch `name`'s current data, insert it into `players`, and start
+/// watching it for further changes. Replaces any previous subscription for
+/// the same name (e.g. a player that restarted under the same service name).
+async fn add_player(
+    conn: &Connection,
+    name: String,
+    tx: &mpsc::UnboundedSender<PlayerEvent>,
+    players: &mut HashMap<String, PlayerData>,
+    subscriptions: &mut HashMap<String, JoinHandle<()>>,
+    show_progress: bool,
+) {
+    players.insert(
+        name.clone(),
+        fetch_player_data(conn, &name, show_progress).await,
+    );
+    let handle = tokio::spawn(watch_player(conn.clone(), name.clone(), tx.clone()));
+    if let Some(old) = subscriptions.insert(name, handle) {
+        old.abort();
+    }
+}
+
+/// Drop `name`'s entry and stop its subscription task.
+fn remove_player(
+    name: &str,
+    players: &mut HashMap<String, PlayerData>,
+    subscriptions: &mut HashMap<String, JoinHandle<()>>,
+) {
+    players.remove(name);
+    if let Some(handle) = subscriptions.remove(name) {
+        handle.abort();
+    }
+}
+
+async fn fetch_player_data(conn: &Connection, name: &str, show_progress: bool) -> PlayerData {
+    let (title, length) = fetch_title_and_length(conn, name).await;
+    let position = if show_progress {
+        fetch_progress(conn, name, length).await
+    } else {
+        None
+    };
+    PlayerData {
+        service_name: name.to_string(),
+        title,
+        volume: fetch_volume(conn, name).await,
+        rank: playback_rank(fetch_status(conn, name).await),
+        position,
+        ..Default::default()
+    }
+}
+
+/// `position`/`rate`, paired with an already-fetched `length` (see
+/// `fetch_title_and_length`) so callers that just read `Metadata` for the
+/// title don't pay for a second `Metadata` round-trip to get the length too.
+async fn fetch_progress(conn: &Connection, name: &str, length: Option<i64>) -> Option<Progress> {
+    let player = PlayerProxy::new(conn, name.to_string()).await.ok()?;
+    let base = player.position().await.unwrap_or(0);
+    let rate = player.rate().await.unwrap_or(1.0);
+    Some(Progress::new(base, rate, is_playing(&player).await, length))
+}
+
+async fn fetch_status(conn: &Connection, name: &str) -> Option<String> {
+    PlayerProxy::new(conn, name.to_string())
+        .await
+        .ok()?
+        .playback_status()
+        .await
+        .ok()
+}
+
+async fn is_playing(player: &PlayerProxy<'_>) -> bool {
+    player.playback_status().await.ok().as_deref() == Some("Playing")
+}
+
+/// `name`'s display title and `mpris:length`, from a single `Metadata` fetch.
+async fn fetch_title_and_length(conn: &Connection, name: &str) -> (Option<String>, Option<i64>) {
+    let Ok(player) = PlayerProxy::new(conn, name.to_string()).await else {
+        return (None, None);
+    };
+    match player.metadata().await {
+        Ok(metadata) => {
+            let length = metadata.length;
+            (metadata.title_line().ok(), length)
+        }
+        Err(_) => (None, None),
+    }
+}
+
+/// `name`'s current `mpris:length`, for the rare case we need it without
+/// already having fetched `Metadata` for something else (e.g. lazily
+/// establishing `Progress` from a bare `Seeked` signal).
+async fn fetch_length(conn: &Connection, name: &str) -> Option<i64> {
+    PlayerProxy::new(conn, name.to_string())
+        .await
+        .ok()?
+        .metadata()
+        .await
+        .ok()?
+        .length
+}
+
+async fn fetch_volume(conn: &Connection, name: &str) -> Option<f64> {
+    PlayerProxy::new(conn, name.to_string())
+        .await
+        .ok()?
+        .volume()
+        .await
+        .ok()
+}
+
+fn player_names(players: &HashMap<String, PlayerData>) -> Vec<String> {
+    players.keys().cloned().collect()
+}
+
+/// Render the current `players`, in `order`, through `mode`.
+fn print_current(
+    mode: &OutputMode,
+    players: &HashMap<String, PlayerData>,
+    order: &[String],
+    marquee_width: Option<usize>,
+    show_progress: bool,
+) {
+    let data: Vec<PlayerData> = order
+        .iter()
+        .filter_map(|n| players.get(n).cloned())
+        .collect();
+    mode.print_update(&data, marquee_width, show_progress);
 }
 
 async fn get_player_names(conn: &Connection) -> Result<Vec<String>, Box<dyn Error>> {
@@ -187,3 +538,52 @@ async fn get_player_names(conn: &Connection) -> Result<Vec<String>, Box<dyn Erro
         .map(|n| n.to_string())
         .collect())
 }
+
+/// Order `names` with the most-recently-active player first.
+///
+/// Prefers `playerctld`'s own recency tracking when it's on the bus (a single
+/// extra D-Bus call), falling back to each player's cached `rank` (Playing,
+/// then Paused, then Stopped) so the "current" player is still a sensible
+/// guess without it — and so reordering on a `Volume`/`PlaybackStatus` event
+/// never has to re-query every other player.
+async fn order_players(
+    conn: &Connection,
+    names: &[String],
+    players: &HashMap<String, PlayerData>,
+) -> Vec<String> {
+    match playerctld_order(conn).await {
+        Ok(order) => {
+            let mut ordered: Vec<String> =
+                order.into_iter().filter(|n| names.contains(n)).collect();
+            ordered.extend(names.iter().filter(|n| !ordered.contains(n)).cloned());
+            ordered
+        }
+        Err(_) => sort_by_rank(names, players),
+    }
+}
+
+async fn playerctld_order(conn: &Connection) -> zbus::Result<Vec<String>> {
+    PlayerctldProxy::new(conn).await?.player_names().await
+}
+
+fn sort_by_rank(names: &[String], players: &HashMap<String, PlayerData>) -> Vec<String> {
+    let mut ranked: Vec<(String, u8)> = names
+        .iter()
+        .map(|n| {
+            (
+                n.clone(),
+                players.get(n).map_or(playback_rank(None), |d| d.rank),
+            )
+        })
+        .collect();
+    ranked.sort_by_key(|(_, rank)| *rank);
+    ranked.into_iter().map(|(n, _)| n).collect()
+}
+
+fn playback_rank(status: Option<String>) -> u8 {
+    match status.as_deref() {
+        Some("Playing") => 0,
+        Some("Paused") => 1,
+        _ => 2,
+    }
+}
@@ -0,0 +1,32 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Parse an optional `--marquee-width=N` flag out of the process arguments.
+///
+/// `N` is a count of grapheme clusters, not bytes or `char`s, so multi-byte
+/// characters and emoji never get split mid-glyph.
+pub fn width_from_args() -> Option<usize> {
+    std::env::args().find_map(|a| {
+        a.strip_prefix("--marquee-width=")
+            .and_then(|w| w.parse().ok())
+    })
+}
+
+/// Render `title` within `width` grapheme clusters.
+///
+/// If `title` already fits, it's returned unchanged. Otherwise it's shown as
+/// a `width`-wide window into the title looped back on itself (with a single
+/// space as the seam), advancing one grapheme per `offset`, so the whole
+/// string scrolls past over time instead of being truncated.
+pub fn scroll(title: &str, width: usize, offset: usize) -> String {
+    let len = title.graphemes(true).count();
+    if len <= width {
+        return title.to_string();
+    }
+    let looped: Vec<&str> = title
+        .graphemes(true)
+        .chain(std::iter::once(" "))
+        .chain(title.graphemes(true))
+        .collect();
+    let start = offset % (len + 1);
+    looped[start..start + width].concat()
+}
@@ -0,0 +1,92 @@
+use serde::Serialize;
+
+use crate::{PlayerData, SERVICE_PREFIX};
+
+/// How `PlayerData` should be rendered to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputMode {
+    /// One `PlayerData::display` line per player (the original behaviour).
+    #[default]
+    Human,
+    /// i3bar/i3blocks JSON, suitable for a `status_command` in i3/sway.
+    I3Bar,
+}
+
+impl OutputMode {
+    /// Parse `--i3bar`/`--human` out of the process arguments, defaulting to `Human`.
+    pub fn from_args() -> Self {
+        if std::env::args().any(|a| a == "--i3bar") {
+            OutputMode::I3Bar
+        } else {
+            OutputMode::Human
+        }
+    }
+
+    /// Emit whatever preamble this mode requires before the first update.
+    pub fn print_header(&self) {
+        if *self == OutputMode::I3Bar {
+            println!("{{\"version\":1}}");
+            println!("[");
+        }
+    }
+
+    /// Render one update for `data`, following this mode's on-wire format.
+    ///
+    /// `marquee_width`, if set, scrolls each player's title to that many
+    /// grapheme clusters instead of printing it in full. `show_progress`
+    /// appends each player's elapsed/total playback position.
+    pub fn print_update(
+        &self,
+        data: &[PlayerData],
+        marquee_width: Option<usize>,
+        show_progress: bool,
+    ) {
+        match self {
+            OutputMode::Human => data
+                .iter()
+                .for_each(|d| println!("{}", d.display(marquee_width, show_progress))),
+            OutputMode::I3Bar => {
+                let blocks: Vec<Block> = data
+                    .iter()
+                    .map(|d| Block::new(d, marquee_width, show_progress))
+                    .collect();
+                println!("{},", serde_json::to_string(&blocks).unwrap_or_default());
+            }
+        }
+    }
+}
+
+/// A single i3bar block, per the protocol's `click_events`/`status_command` JSON schema.
+#[derive(Debug, Serialize)]
+struct Block {
+    full_text: String,
+    short_text: String,
+    instance: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<&'static str>,
+}
+
+impl Block {
+    fn new(data: &PlayerData, marquee_width: Option<usize>, show_progress: bool) -> Self {
+        let instance = data
+            .service_name
+            .strip_prefix(SERVICE_PREFIX)
+            .unwrap_or(&data.service_name)
+            .to_string();
+        let progress = data.progress_suffix(show_progress);
+        match data.display_title(marquee_width) {
+            Some(title) => Block {
+                full_text: format!("{}: {}{}", instance, title, progress),
+                short_text: title,
+                instance,
+                color: None,
+            },
+            None => Block {
+                full_text: format!("{}: Nothing", instance),
+                short_text: "Nothing".to_string(),
+                instance,
+                color: Some("#888888"),
+            },
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::time::Instant;
+
+/// Parse an optional `--progress` flag enabling the elapsed/total display.
+pub fn enabled_from_args() -> bool {
+    std::env::args().any(|a| a == "--progress")
+}
+
+/// A player's playback position, interpolated between the infrequent
+/// updates MPRIS actually gives us (`Seeked` signals and `PlaybackStatus`
+/// changes) rather than polled on every tick.
+#[derive(Debug, Clone)]
+pub struct Progress {
+    /// The position, in microseconds, as of `set_at`.
+    base: i64,
+    set_at: Instant,
+    rate: f64,
+    playing: bool,
+    pub length: Option<i64>,
+}
+
+impl Progress {
+    pub fn new(base: i64, rate: f64, playing: bool, length: Option<i64>) -> Self {
+        Progress {
+            base,
+            set_at: Instant::now(),
+            rate,
+            playing,
+            length,
+        }
+    }
+
+    /// The current position in microseconds: `base` if paused, otherwise
+    /// `base` plus however much wall-clock time has passed since `set_at`,
+    /// scaled by `rate`.
+    pub fn micros(&self) -> i64 {
+        if !self.playing {
+            return self.base;
+        }
+        self.base + (self.set_at.elapsed().as_secs_f64() * self.rate * 1_000_000.0) as i64
+    }
+
+    /// Re-anchor to a freshly reported `position`, e.g. from a `Seeked` signal.
+    pub fn seek_to(&mut self, position: i64) {
+        self.base = position;
+        self.set_at = Instant::now();
+    }
+
+    /// Freeze or resume interpolation from the current computed position,
+    /// e.g. on a `PlaybackStatus` change.
+    pub fn set_playing(&mut self, playing: bool) {
+        self.base = self.micros();
+        self.set_at = Instant::now();
+        self.playing = playing;
+    }
+
+    /// Render as `elapsed / total`, e.g. `1:23 / 4:56`, or just `elapsed` if
+    /// `length` isn't known.
+    pub fn render(&self) -> String {
+        match self.length {
+            Some(length) => format!(
+                "{} / {}",
+                format_micros(self.micros()),
+                format_micros(length)
+            ),
+            None => format_micros(self.micros()),
+        }
+    }
+}
+
+fn format_micros(micros: i64) -> String {
+    let total_seconds = (micros.max(0) / 1_000_000) as u64;
+    format!("{}:{:02}", total_seconds / 60, total_seconds % 60)
+}
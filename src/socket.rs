@@ -0,0 +1,92 @@
+use std::{error::Error, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    net::{UnixListener, UnixStream},
+};
+use zbus::Connection;
+
+use crate::{send_command, Command, PlayerProxy, SERVICE_PREFIX};
+
+/// How much a single `VolumeUp`/`VolumeDown` nudges `Player.Volume`.
+const VOLUME_STEP: f64 = 0.05;
+
+/// One action read off the control socket, e.g. from a bar's click handler.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SocketCommand {
+    pub kind: CommandKind,
+    /// The target player's service name with `SERVICE_PREFIX` stripped,
+    /// i.e. the same `instance` an i3bar block reports back on click.
+    pub instance: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum CommandKind {
+    PlayPause,
+    Next,
+    Prev,
+    VolumeUp,
+    VolumeDown,
+}
+
+/// Parse an optional `--socket=PATH` flag, defaulting under `XDG_RUNTIME_DIR`.
+pub fn path_from_args() -> PathBuf {
+    std::env::args()
+        .find_map(|a| a.strip_prefix("--socket=").map(PathBuf::from))
+        .unwrap_or_else(default_path)
+}
+
+fn default_path() -> PathBuf {
+    let runtime_dir = std::env::var("XDG_RUNTIME_DIR").unwrap_or_else(|_| "/tmp".to_string());
+    PathBuf::from(runtime_dir).join("mpris-poc.sock")
+}
+
+/// Accept connections on `path` forever, dispatching each newline-delimited
+/// `SocketCommand` to its target player over `conn`.
+///
+/// Reuses the caller's D-Bus connection rather than opening a new one per
+/// click.
+pub async fn listen(conn: Connection, path: PathBuf) -> std::io::Result<()> {
+    let _ = std::fs::remove_file(&path);
+    let listener = UnixListener::bind(&path)?;
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let conn = conn.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, conn).await {
+                eprintln!("command socket: {e}");
+            }
+        });
+    }
+}
+
+async fn handle_connection(stream: UnixStream, conn: Connection) -> Result<(), Box<dyn Error>> {
+    let mut lines = BufReader::new(stream).lines();
+    while let Some(line) = lines.next_line().await? {
+        dispatch(&conn, serde_json::from_str(&line)?).await?;
+    }
+    Ok(())
+}
+
+async fn dispatch(conn: &Connection, command: SocketCommand) -> Result<(), Box<dyn Error>> {
+    let name = format!("{}{}", SERVICE_PREFIX, command.instance);
+    match command.kind {
+        CommandKind::PlayPause => send_command(conn, &name, Command::PlayPause).await,
+        CommandKind::Next => send_command(conn, &name, Command::Next).await,
+        CommandKind::Prev => send_command(conn, &name, Command::Previous).await,
+        CommandKind::VolumeUp => nudge_volume(conn, &name, VOLUME_STEP).await,
+        CommandKind::VolumeDown => nudge_volume(conn, &name, -VOLUME_STEP).await,
+    }
+}
+
+async fn nudge_volume(conn: &Connection, name: &str, delta: f64) -> Result<(), Box<dyn Error>> {
+    let player = PlayerProxy::new(conn, name.to_string()).await?;
+    let volume = player.volume().await.unwrap_or(0.0);
+    send_command(
+        conn,
+        name,
+        Command::SetVolume((volume + delta).clamp(0.0, 1.0)),
+    )
+    .await
+}